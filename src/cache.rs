@@ -0,0 +1,70 @@
+//! Shared, size-bounded cache for resources that repeat across pages of the same document.
+//!
+//! Only standard-font bytes are cached here. An earlier revision of this module also declared
+//! `CacheKey` variants for per-glyph and per-image-XObject caching, but `hayro::InterpreterSettings`
+//! only exposes a `font_resolver` hook — there's no decode-level callback for individual glyph
+//! rasterizations or image XObjects to key a cache on, short of forking the renderer. Those
+//! variants were never constructed and have been removed; revisit this if `hayro` grows a hook
+//! for it.
+
+use lru::LruCache;
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+/// Key identifying a cacheable rendered resource.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CacheKey {
+    /// A resolved standard font, keyed by the `hayro::StandardFont` discriminant.
+    StandardFont(u8),
+}
+
+/// A cached font: its face index alongside the raw bytes, `Arc`-wrapped so a cache hit is a
+/// refcount bump instead of a copy of the (possibly several-hundred-KB) font data.
+pub type CachedFont = (u32, Arc<Vec<u8>>);
+
+/// A shared LRU cache of decoded bitmaps/resources, safe to hand to multiple page-rendering
+/// workers via `Arc`. Bounded by entry count, not byte size, matching `lru::LruCache`.
+#[derive(Clone)]
+pub struct RenderCache {
+    inner: Arc<Mutex<LruCache<CacheKey, CachedFont>>>,
+}
+
+impl RenderCache {
+    /// Create a cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::new(1).unwrap());
+        RenderCache {
+            inner: Arc::new(Mutex::new(LruCache::new(capacity))),
+        }
+    }
+
+    /// Fetch a cached entry, or compute and insert it via `compute` on a miss.
+    ///
+    /// The lock is released while `compute` runs, so a miss on one key never blocks other
+    /// threads touching the cache — only the (cheap) lookup and insert are serialized. Two
+    /// threads racing on the same missing key may both call `compute`; the second one's result
+    /// simply overwrites the first's in the cache, which is a fine tradeoff since `compute` here
+    /// is a pure, idempotent byte lookup rather than anything with side effects. Cloning a hit or
+    /// a freshly computed entry is an `Arc::clone` of the font bytes plus a `u32` copy, not a
+    /// buffer copy.
+    pub fn get_or_insert_with(
+        &self,
+        key: CacheKey,
+        compute: impl FnOnce() -> CachedFont,
+    ) -> CachedFont {
+        if let Some(hit) = self.inner.lock().unwrap().get(&key) {
+            return hit.clone();
+        }
+        let value = compute();
+        self.inner.lock().unwrap().put(key, value.clone());
+        value
+    }
+}
+
+impl Default for RenderCache {
+    /// 256 entries comfortably covers the base-14 fonts used by any single document.
+    fn default() -> Self {
+        RenderCache::new(256)
+    }
+}
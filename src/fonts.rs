@@ -0,0 +1,120 @@
+//! Embedded standard-14 font replacements and the resolver used when rendering raster output.
+
+use crate::cache::{CacheKey, RenderCache};
+use hayro::{FontData, StandardFont};
+use std::sync::Arc;
+
+/// Open-source replacements for the 14 standard PDF fonts, embedded as raw font bytes (see
+/// `assets/fonts/LICENSE`) so pages that rely on them (Helvetica, Times, Courier, Symbol,
+/// ZapfDingbats and their bold/italic variants) render text instead of dropping the glyphs.
+///
+/// DejaVu Sans/Serif/Sans Mono stand in for Helvetica/Times/Courier. DejaVu ships no dedicated
+/// symbol or dingbats face, so Symbol and ZapfDingbats fall back to DejaVu Sans — an approximate
+/// substitute that covers common punctuation but not the full PDF symbol glyph set.
+macro_rules! embedded_font {
+    ($path:literal) => {
+        FontData::new(Arc::new(include_bytes!($path).to_vec()))
+    };
+}
+
+fn embedded_standard_font(font: StandardFont) -> (FontData, u32) {
+    let data = match font {
+        StandardFont::Helvetica => embedded_font!("../assets/fonts/DejaVuSans.ttf"),
+        StandardFont::HelveticaBold => embedded_font!("../assets/fonts/DejaVuSans-Bold.ttf"),
+        StandardFont::HelveticaOblique => {
+            embedded_font!("../assets/fonts/DejaVuSans-Oblique.ttf")
+        }
+        StandardFont::HelveticaBoldOblique => {
+            embedded_font!("../assets/fonts/DejaVuSans-BoldOblique.ttf")
+        }
+        StandardFont::Times => embedded_font!("../assets/fonts/DejaVuSerif.ttf"),
+        StandardFont::TimesBold => embedded_font!("../assets/fonts/DejaVuSerif-Bold.ttf"),
+        StandardFont::TimesItalic => embedded_font!("../assets/fonts/DejaVuSerif-Italic.ttf"),
+        StandardFont::TimesBoldItalic => {
+            embedded_font!("../assets/fonts/DejaVuSerif-BoldItalic.ttf")
+        }
+        StandardFont::Courier => embedded_font!("../assets/fonts/DejaVuSansMono.ttf"),
+        StandardFont::CourierBold => embedded_font!("../assets/fonts/DejaVuSansMono-Bold.ttf"),
+        StandardFont::CourierOblique => {
+            embedded_font!("../assets/fonts/DejaVuSansMono-Oblique.ttf")
+        }
+        StandardFont::CourierBoldOblique => {
+            embedded_font!("../assets/fonts/DejaVuSansMono-BoldOblique.ttf")
+        }
+        StandardFont::Symbol => embedded_font!("../assets/fonts/DejaVuSans.ttf"),
+        StandardFont::ZapfDingbats => embedded_font!("../assets/fonts/DejaVuSans.ttf"),
+    };
+    (data, 0)
+}
+
+/// Controls how the renderer resolves the 14 standard PDF fonts (and the base-14 substitution
+/// used for `FontQuery::Fallback`).
+///
+/// Defaults to the embedded DejaVu replacements; callers that need a different font (house
+/// fonts, licensing constraints) can supply their own resolver with [`Self::with_resolver`].
+#[derive(Clone)]
+pub struct FontConfig {
+    resolver: Arc<dyn Fn(StandardFont) -> Option<(FontData, u32)> + Send + Sync>,
+}
+
+impl Default for FontConfig {
+    fn default() -> Self {
+        FontConfig {
+            resolver: Arc::new(|font| Some(embedded_standard_font(font))),
+        }
+    }
+}
+
+impl FontConfig {
+    /// Use a custom resolver instead of the embedded standard-font set.
+    pub fn with_resolver(
+        resolver: impl Fn(StandardFont) -> Option<(FontData, u32)> + Send + Sync + 'static,
+    ) -> Self {
+        FontConfig {
+            resolver: Arc::new(resolver),
+        }
+    }
+
+    /// Wrap a custom resolver with a [`RenderCache`] so repeated lookups of the same standard
+    /// font (e.g. across the many pages of a batch split) reuse the same `Arc`-backed bytes
+    /// instead of paying the resolver's cost again. Share one `cache` across a
+    /// `split_pdf_parallel` run so concurrent page workers benefit from each other's lookups.
+    pub fn with_cache(
+        resolver: impl Fn(StandardFont) -> Option<(FontData, u32)> + Send + Sync + 'static,
+        cache: RenderCache,
+    ) -> Self {
+        FontConfig {
+            resolver: Arc::new(move |font| {
+                let key = CacheKey::StandardFont(font as u8);
+                let (face_index, bytes) = cache.get_or_insert_with(key, || {
+                    resolver(font)
+                        .map(|(data, face_index)| (face_index, Arc::new(data.as_ref().to_vec())))
+                        .unwrap_or((0, Arc::new(Vec::new())))
+                });
+                if bytes.is_empty() {
+                    None
+                } else {
+                    Some((FontData::new(bytes), face_index))
+                }
+            }),
+        }
+    }
+
+    /// The default embedded resolver, wrapped in a [`RenderCache`]. Equivalent to
+    /// `FontConfig::with_cache` applied to the same resolver [`Default`] uses, but doesn't
+    /// require the caller to have their own resolver closure on hand — just a cache to share
+    /// across a `split_pdf_parallel` run's workers.
+    pub fn cached(cache: RenderCache) -> Self {
+        FontConfig::with_cache(|font| Some(embedded_standard_font(font)), cache)
+    }
+
+    pub(crate) fn resolve(&self, font: StandardFont) -> Option<(FontData, u32)> {
+        (self.resolver)(font)
+    }
+}
+
+impl std::fmt::Debug for FontConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FontConfig").finish_non_exhaustive()
+    }
+}
@@ -1,4 +1,4 @@
-use hayro::{FontData, FontQuery, InterpreterSettings, RenderSettings, StandardFont};
+use hayro::{FontQuery, InterpreterSettings, RenderSettings};
 use hayro_syntax::Pdf;
 use krilla::page::PageSettings;
 use krilla::Document;
@@ -8,12 +8,72 @@ use tiny_skia::IntSize;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+mod cache;
+mod fonts;
+mod options;
+pub use cache::{CacheKey, RenderCache};
+pub use fonts::FontConfig;
+pub use options::{PageRange, PageSelection, SplitOptions};
+
 /// Output format for split pages
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "wasm", derive(serde::Deserialize))]
 pub enum OutputFormat {
     Pdf,
     Png,
+    /// JPEG with the given quality (1-100).
+    #[cfg(feature = "jpeg")]
+    Jpeg { quality: u8 },
+    /// WebP with the given quality (0.0-100.0), or lossless if `lossless` is set.
+    #[cfg(feature = "webp")]
+    WebP { quality: f32, lossless: bool },
+    Tiff,
+}
+
+impl OutputFormat {
+    /// Identifier used for the `PageResult::format` field and the CLI file extension lookup.
+    ///
+    /// `Pdf`/`Png` keep their original `"pdf"`/`"png"` values for compatibility with existing
+    /// consumers; formats added after those two identify themselves by MIME type instead, since
+    /// there's no established short tag for them to collide with.
+    fn format_tag(&self) -> &'static str {
+        match self {
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Png => "png",
+            #[cfg(feature = "jpeg")]
+            OutputFormat::Jpeg { .. } => "image/jpeg",
+            #[cfg(feature = "webp")]
+            OutputFormat::WebP { .. } => "image/webp",
+            OutputFormat::Tiff => "image/tiff",
+        }
+    }
+
+    /// Whether this format's encoder truncates the alpha channel with no compositing step of
+    /// its own, meaning `extract_page_raster` needs to flatten onto a background first or
+    /// transparent regions come out however the encoder happens to interpret a zeroed alpha
+    /// channel (black, for JPEG).
+    fn drops_alpha_without_compositing(&self) -> bool {
+        #[cfg(feature = "jpeg")]
+        if matches!(self, OutputFormat::Jpeg { .. }) {
+            return true;
+        }
+        false
+    }
+}
+
+/// List the output formats supported in this build, e.g. `["pdf", "png", "jpeg", "webp", "tiff"]`.
+///
+/// JPEG and WebP are gated behind their respective cargo features; callers (the CLI's
+/// `--format` value list, the wasm constructor's string matcher) should use this instead of
+/// hardcoding the set so they stay in sync with what's actually compiled in.
+pub fn supported_output_formats() -> Vec<&'static str> {
+    let mut formats = vec!["pdf", "png"];
+    #[cfg(feature = "jpeg")]
+    formats.push("jpeg");
+    #[cfg(feature = "webp")]
+    formats.push("webp");
+    formats.push("tiff");
+    formats
 }
 
 /// Result of splitting a single page
@@ -26,6 +86,40 @@ pub struct PageResult {
     pub format: String,
 }
 
+/// Target PDF version for emitted documents, mirroring cairo's `PdfVersion`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PdfVersion {
+    V1_4,
+    V1_5,
+    V1_6,
+    #[default]
+    V1_7,
+    V2_0,
+}
+
+/// Document-level metadata copied from a source PDF into an extracted page.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Option<String>,
+    pub creation_date: Option<String>,
+}
+
+/// Options controlling what `split_pdf`/`extract_page_pdf` preserve from the source document
+/// when emitting `OutputFormat::Pdf` pages. Has no effect on raster outputs.
+#[derive(Debug, Clone, Default)]
+pub struct PdfWriteOptions {
+    /// Copy Title/Author/Subject/Keywords/CreationDate from the source PDF's document info.
+    pub preserve_metadata: bool,
+    /// Target PDF version for the output document.
+    pub version: PdfVersion,
+    /// Retain the subset of the source outline/bookmark tree whose destinations land on the
+    /// extracted page.
+    pub preserve_outline: bool,
+}
+
 #[cfg(feature = "wasm")]
 mod serde_bytes {
     use base64::{engine::general_purpose, Engine as _};
@@ -50,7 +144,16 @@ mod serde_bytes {
 }
 
 /// Split a PDF into individual pages
-pub fn split_pdf(pdf_data: &[u8], format: OutputFormat) -> Result<Vec<PageResult>, String> {
+///
+/// Only pages selected by `split_options.pages` are rendered at all — a non-selected page is
+/// skipped outright rather than rendered and discarded.
+pub fn split_pdf(
+    pdf_data: &[u8],
+    format: OutputFormat,
+    write_options: &PdfWriteOptions,
+    font_config: &FontConfig,
+    split_options: &SplitOptions,
+) -> Result<Vec<PageResult>, String> {
     // Load the PDF using hayro-syntax
     let pdf_data_arc = Arc::new(pdf_data.to_vec());
     let pdf =
@@ -59,35 +162,147 @@ pub fn split_pdf(pdf_data: &[u8], format: OutputFormat) -> Result<Vec<PageResult
     let num_pages = pdf.pages().len();
     let mut results = Vec::with_capacity(num_pages);
 
-    // Split each page
+    // Split each selected page
     for (i, page) in pdf.pages().iter().enumerate() {
+        let page_number = i + 1;
+        if !split_options.pages.contains(page_number) {
+            continue;
+        }
+
         let (width, height) = page.render_dimensions();
 
         let data = match format {
-            OutputFormat::Pdf => extract_page_pdf(&pdf, i, width, height)?,
-            OutputFormat::Png => extract_page_png(&pdf, i, width, height)?,
+            OutputFormat::Pdf => extract_page_pdf(&pdf, i, width, height, write_options)?,
+            _ => extract_page_raster(&pdf, i, width, height, format, font_config, split_options)?,
         };
 
         results.push(PageResult {
-            page_number: i + 1,
+            page_number,
             data,
-            format: match format {
-                OutputFormat::Pdf => "pdf".to_string(),
-                OutputFormat::Png => "png".to_string(),
-            },
+            format: format.format_tag().to_string(),
         });
     }
 
     Ok(results)
 }
 
+/// Split a PDF into individual pages, rendering pages concurrently across a rayon thread pool.
+///
+/// Opt into this for large documents where sequential rendering dominates wall-clock time.
+/// Page order in the returned `Vec` matches `split_pdf` even though pages finish out of order,
+/// since collecting a rayon `IndexedParallelIterator` reassembles results by index. Pass a
+/// `font_config` built with [`FontConfig::with_cache`] to let concurrent workers share decoded
+/// font bytes instead of re-resolving them per page.
+pub fn split_pdf_parallel(
+    pdf_data: &[u8],
+    format: OutputFormat,
+    write_options: &PdfWriteOptions,
+    font_config: &FontConfig,
+    split_options: &SplitOptions,
+) -> Result<Vec<PageResult>, String> {
+    use rayon::prelude::*;
+
+    let pdf_data_arc = Arc::new(pdf_data.to_vec());
+    let pdf =
+        Arc::new(Pdf::new(pdf_data_arc).map_err(|e| format!("Failed to parse PDF: {:?}", e))?);
+    let num_pages = pdf.pages().len();
+
+    let selected_pages: Vec<usize> = (0..num_pages)
+        .filter(|&i| split_options.pages.contains(i + 1))
+        .collect();
+
+    selected_pages
+        .into_par_iter()
+        .map(|i| -> Result<PageResult, String> {
+            let page = pdf
+                .pages()
+                .get(i)
+                .ok_or_else(|| format!("Page {} not found", i + 1))?;
+            let (width, height) = page.render_dimensions();
+
+            let data = match format {
+                OutputFormat::Pdf => extract_page_pdf(&pdf, i, width, height, write_options)?,
+                _ => {
+                    extract_page_raster(&pdf, i, width, height, format, font_config, split_options)?
+                }
+            };
+
+            Ok(PageResult {
+                page_number: i + 1,
+                data,
+                format: format.format_tag().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Merge several PDFs into a single multi-page PDF, preserving each source page's own size.
+///
+/// Pages are appended in the order `inputs` are given, each followed by its pages in order.
+/// Because every page gets its own `PageSettings` taken from `render_dimensions()`, a letter
+/// page and an A4 page can coexist in the same output without either being resized.
+pub fn merge_pdfs(inputs: &[Vec<u8>]) -> Result<Vec<u8>, String> {
+    let mut document = Document::new();
+
+    for input in inputs {
+        let pdf_data_arc = Arc::new(input.clone());
+        let pdf = Arc::new(
+            Pdf::new(pdf_data_arc).map_err(|e| format!("Failed to parse PDF: {:?}", e))?,
+        );
+        let krilla_pdf = krilla::pdf::PdfDocument::new(pdf.clone());
+
+        for (i, page) in pdf.pages().iter().enumerate() {
+            let (width, height) = page.render_dimensions();
+            let settings = PageSettings::new(width, height);
+            let mut doc_page = document.start_page_with(settings);
+            let mut surface = doc_page.surface();
+
+            let size = krilla::geom::Size::from_wh(width, height)
+                .ok_or_else(|| format!("Invalid page dimensions: {}x{}", width, height))?;
+            surface.draw_pdf_page(&krilla_pdf, size, i);
+
+            drop(surface);
+            doc_page.finish();
+        }
+    }
+
+    document
+        .finish()
+        .map_err(|e| format!("Failed to serialize PDF: {:?}", e))
+}
+
 fn extract_page_pdf(
     pdf: &Arc<Pdf>,
     page_index: usize,
     width: f32,
     height: f32,
+    write_options: &PdfWriteOptions,
 ) -> Result<Vec<u8>, String> {
-    let mut document = Document::new();
+    let configuration = krilla::configure::Configuration::new()
+        .with_version(write_options.version.into());
+    let mut document = Document::new_with(configuration);
+
+    if write_options.preserve_metadata {
+        let source_metadata = source_document_metadata(pdf);
+        let mut metadata = krilla::metadata::Metadata::new();
+        if let Some(title) = source_metadata.title {
+            metadata = metadata.title(title);
+        }
+        if let Some(author) = source_metadata.author {
+            metadata = metadata.authors(vec![author]);
+        }
+        if let Some(subject) = source_metadata.subject {
+            metadata = metadata.subject(subject);
+        }
+        if let Some(keywords) = source_metadata.keywords {
+            metadata = metadata.keywords(vec![keywords]);
+        }
+        if let Some(creation_date) = source_metadata.creation_date {
+            metadata = metadata.creation_date(creation_date);
+        }
+        document.set_metadata(metadata);
+    }
+
     let settings = PageSettings::new(width, height);
     let mut page = document.start_page_with(settings);
     let mut surface = page.surface();
@@ -100,6 +315,12 @@ fn extract_page_pdf(
     drop(surface);
     page.finish();
 
+    if write_options.preserve_outline {
+        if let Some(outline) = outline_for_page(pdf, page_index) {
+            document.set_outline(outline);
+        }
+    }
+
     let pdf_bytes = document
         .finish()
         .map_err(|e| format!("Failed to serialize PDF: {:?}", e))?;
@@ -107,25 +328,96 @@ fn extract_page_pdf(
     Ok(pdf_bytes)
 }
 
-fn extract_page_png(
+impl From<PdfVersion> for krilla::configure::PdfVersion {
+    fn from(version: PdfVersion) -> Self {
+        match version {
+            PdfVersion::V1_4 => krilla::configure::PdfVersion::Pdf14,
+            PdfVersion::V1_5 => krilla::configure::PdfVersion::Pdf15,
+            PdfVersion::V1_6 => krilla::configure::PdfVersion::Pdf16,
+            PdfVersion::V1_7 => krilla::configure::PdfVersion::Pdf17,
+            PdfVersion::V2_0 => krilla::configure::PdfVersion::Pdf20,
+        }
+    }
+}
+
+/// Best-effort extraction of Title/Author/Subject/Keywords/CreationDate from the source PDF's
+/// document info dictionary.
+fn source_document_metadata(pdf: &Pdf) -> DocumentMetadata {
+    let info = pdf.metadata();
+    DocumentMetadata {
+        title: info.title.clone(),
+        author: info.author.clone(),
+        subject: info.subject.clone(),
+        keywords: info.keywords.clone(),
+        creation_date: info.creation_date.clone(),
+    }
+}
+
+/// Walk the source PDF's outline tree and keep only the entries (and their ancestors) whose
+/// destination lands on `page_index`, rebuilt as a krilla outline.
+fn outline_for_page(pdf: &Pdf, page_index: usize) -> Option<krilla::outline::Outline> {
+    let source_outline = pdf.outline()?;
+
+    fn collect(
+        items: &[hayro_syntax::OutlineItem],
+        page_index: usize,
+    ) -> Vec<krilla::outline::OutlineNode> {
+        let mut kept = Vec::new();
+        for item in items {
+            let children = collect(&item.children, page_index);
+            let targets_page = item
+                .destination
+                .as_ref()
+                .map(|dest| dest.page_index == page_index)
+                .unwrap_or(false);
+            if targets_page || !children.is_empty() {
+                let mut node = krilla::outline::OutlineNode::new(item.title.clone());
+                for child in children {
+                    node.push_child(child);
+                }
+                kept.push(node);
+            }
+        }
+        kept
+    }
+
+    let nodes = collect(&source_outline.items, page_index);
+    if nodes.is_empty() {
+        return None;
+    }
+
+    let mut outline = krilla::outline::Outline::new();
+    for node in nodes {
+        outline.push_child(node);
+    }
+    Some(outline)
+}
+
+/// Render a page to an RGBA [`tiny_skia::Pixmap`] and encode it as `format`.
+///
+/// Rendering always happens once, at the requested DPI, into a premultiplied RGBA buffer;
+/// `format` only selects the encoder applied to that buffer afterwards.
+fn extract_page_raster(
     pdf: &Arc<Pdf>,
     page_index: usize,
     width: f32,
     height: f32,
+    format: OutputFormat,
+    font_config: &FontConfig,
+    split_options: &SplitOptions,
 ) -> Result<Vec<u8>, String> {
-    const DPI: f32 = 300.0;
     const POINTS_PER_INCH: f32 = 72.0;
-    let pixel_per_pt = DPI / POINTS_PER_INCH;
+    let pixel_per_pt = split_options.dpi / POINTS_PER_INCH;
 
     let out_width = (width * pixel_per_pt).round() as u32;
     let out_height = (height * pixel_per_pt).round() as u32;
 
-    let select_standard_font = |_font: StandardFont| -> Option<(FontData, u32)> { None };
+    let font_config = font_config.clone();
 
     let interpreter_settings = InterpreterSettings {
         font_resolver: Arc::new(move |query| match query {
-            FontQuery::Standard(s) => select_standard_font(*s),
-            FontQuery::Fallback(f) => select_standard_font(f.pick_standard_font()),
+            FontQuery::Standard(s) => font_config.resolve(*s),
+            FontQuery::Fallback(f) => font_config.resolve(f.pick_standard_font()),
         }),
         warning_sink: Arc::new(|_| {}),
     };
@@ -135,6 +427,7 @@ fn extract_page_png(
         y_scale: out_height as f32 / height,
         width: Some(out_width as u16),
         height: Some(out_height as u16),
+        anti_alias: split_options.antialias,
     };
 
     let page = pdf
@@ -144,16 +437,101 @@ fn extract_page_png(
 
     let hayro_pix = hayro::render(page, &interpreter_settings, &render_settings);
 
-    let pixmap = tiny_skia::Pixmap::from_vec(
+    let mut pixmap = tiny_skia::Pixmap::from_vec(
         hayro_pix.take_u8(),
         IntSize::from_wh(out_width, out_height)
             .ok_or_else(|| "Invalid output dimensions".to_string())?,
     )
     .ok_or_else(|| "Failed to create pixmap".to_string())?;
 
-    pixmap
-        .encode_png()
-        .map_err(|e| format!("Failed to encode PNG: {}", e))
+    if let Some(background) = split_options.background {
+        pixmap = flatten_onto_background(&pixmap, background)?;
+    } else if format.drops_alpha_without_compositing() {
+        // Without an explicit background, flatten onto white first so transparent regions come
+        // out white instead of black once the encoder truncates the alpha channel.
+        pixmap = flatten_onto_background(&pixmap, tiny_skia::Color::WHITE)?;
+    }
+
+    encode_pixmap(&pixmap, format)
+}
+
+/// Composite a rendered page over a solid background color, dropping its alpha channel.
+///
+/// Useful ahead of encoders that don't support transparency (JPEG) or simply when the caller
+/// wants a flat, opaque image.
+fn flatten_onto_background(
+    pixmap: &tiny_skia::Pixmap,
+    background: tiny_skia::Color,
+) -> Result<tiny_skia::Pixmap, String> {
+    let mut flattened = tiny_skia::Pixmap::new(pixmap.width(), pixmap.height())
+        .ok_or_else(|| "Failed to create background pixmap".to_string())?;
+    flattened.fill(background);
+    flattened.draw_pixmap(
+        0,
+        0,
+        pixmap.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        None,
+    );
+    Ok(flattened)
+}
+
+/// Encode a rendered page's RGBA pixmap according to `format`.
+fn encode_pixmap(pixmap: &tiny_skia::Pixmap, format: OutputFormat) -> Result<Vec<u8>, String> {
+    match format {
+        OutputFormat::Png => pixmap
+            .encode_png()
+            .map_err(|e| format!("Failed to encode PNG: {}", e)),
+        #[cfg(feature = "jpeg")]
+        OutputFormat::Jpeg { quality } => {
+            let image = straight_alpha_rgba_image(pixmap)?;
+            let mut bytes = Vec::new();
+            let encoder =
+                image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality);
+            image::DynamicImage::ImageRgba8(image)
+                .into_rgb8()
+                .write_with_encoder(encoder)
+                .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
+            Ok(bytes)
+        }
+        #[cfg(feature = "webp")]
+        OutputFormat::WebP { quality, lossless } => {
+            let image = straight_alpha_rgba_image(pixmap)?;
+            let encoder = webp::Encoder::from_rgba(&image, image.width(), image.height());
+            let encoded = if lossless {
+                encoder.encode_lossless()
+            } else {
+                encoder.encode(quality)
+            };
+            Ok(encoded.to_vec())
+        }
+        OutputFormat::Tiff => {
+            let image = straight_alpha_rgba_image(pixmap)?;
+            let mut bytes = Vec::new();
+            image::DynamicImage::ImageRgba8(image)
+                .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Tiff)
+                .map_err(|e| format!("Failed to encode TIFF: {}", e))?;
+            Ok(bytes)
+        }
+        OutputFormat::Pdf => unreachable!("PDF output is handled by extract_page_pdf"),
+    }
+}
+
+/// Convert a premultiplied-alpha [`tiny_skia::Pixmap`] into a straight-alpha `image::RgbaImage`,
+/// as required by encoders from the `image` crate.
+fn straight_alpha_rgba_image(pixmap: &tiny_skia::Pixmap) -> Result<image::RgbaImage, String> {
+    let mut data = pixmap.data().to_vec();
+    for pixel in data.chunks_exact_mut(4) {
+        let alpha = pixel[3];
+        if alpha != 0 && alpha != 255 {
+            for channel in &mut pixel[..3] {
+                *channel = ((*channel as u16 * 255) / alpha as u16) as u8;
+            }
+        }
+    }
+    image::RgbaImage::from_raw(pixmap.width(), pixmap.height(), data)
+        .ok_or_else(|| "Failed to build RGBA image from pixmap".to_string())
 }
 
 // Streaming API for processing pages one at a time
@@ -162,6 +540,9 @@ fn extract_page_png(
 pub struct PdfSplitter {
     pdf: Arc<Pdf>,
     format: OutputFormat,
+    write_options: PdfWriteOptions,
+    font_config: FontConfig,
+    split_options: SplitOptions,
     current_page: usize,
     total_pages: usize,
 }
@@ -175,7 +556,21 @@ impl PdfSplitter {
         let output_format = match format.to_lowercase().as_str() {
             "pdf" => OutputFormat::Pdf,
             "png" => OutputFormat::Png,
-            _ => return Err(JsValue::from_str("Invalid format. Use 'pdf' or 'png'")),
+            #[cfg(feature = "jpeg")]
+            "jpeg" | "jpg" => OutputFormat::Jpeg { quality: 85 },
+            #[cfg(feature = "webp")]
+            "webp" => OutputFormat::WebP {
+                quality: 80.0,
+                lossless: false,
+            },
+            "tiff" => OutputFormat::Tiff,
+            _ => {
+                return Err(JsValue::from_str(&format!(
+                    "Invalid format '{}'. Supported formats: {}",
+                    format,
+                    supported_output_formats().join(", ")
+                )))
+            }
         };
 
         let pdf_data_arc = Arc::new(bytes.to_vec());
@@ -189,6 +584,9 @@ impl PdfSplitter {
         Ok(PdfSplitter {
             pdf,
             format: output_format,
+            write_options: PdfWriteOptions::default(),
+            font_config: FontConfig::default(),
+            split_options: SplitOptions::default(),
             current_page: 0,
             total_pages,
         })
@@ -226,19 +624,30 @@ impl PdfSplitter {
         let (width, height) = page.render_dimensions();
 
         let data = match self.format {
-            OutputFormat::Pdf => extract_page_pdf(&self.pdf, self.current_page, width, height)
-                .map_err(|e| JsValue::from_str(&e))?,
-            OutputFormat::Png => extract_page_png(&self.pdf, self.current_page, width, height)
-                .map_err(|e| JsValue::from_str(&e))?,
+            OutputFormat::Pdf => extract_page_pdf(
+                &self.pdf,
+                self.current_page,
+                width,
+                height,
+                &self.write_options,
+            )
+            .map_err(|e| JsValue::from_str(&e))?,
+            _ => extract_page_raster(
+                &self.pdf,
+                self.current_page,
+                width,
+                height,
+                self.format,
+                &self.font_config,
+                &self.split_options,
+            )
+            .map_err(|e| JsValue::from_str(&e))?,
         };
 
         let result = PageResult {
             page_number: self.current_page + 1,
             data,
-            format: match self.format {
-                OutputFormat::Pdf => "pdf".to_string(),
-                OutputFormat::Png => "png".to_string(),
-            },
+            format: self.format.format_tag().to_string(),
         };
 
         self.current_page += 1;
@@ -247,3 +656,49 @@ impl PdfSplitter {
             .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_keeps_opaque_pixel_color() {
+        let mut pixmap = tiny_skia::Pixmap::new(1, 1).unwrap();
+        pixmap.fill(tiny_skia::Color::from_rgba8(0, 255, 0, 255));
+
+        let flattened =
+            flatten_onto_background(&pixmap, tiny_skia::Color::from_rgba8(255, 0, 0, 255))
+                .unwrap();
+
+        let pixel = flattened.pixels()[0];
+        assert_eq!((pixel.red(), pixel.green(), pixel.blue()), (0, 255, 0));
+    }
+
+    #[test]
+    fn flatten_fills_transparent_pixel_with_background() {
+        let pixmap = tiny_skia::Pixmap::new(1, 1).unwrap();
+
+        let flattened =
+            flatten_onto_background(&pixmap, tiny_skia::Color::from_rgba8(0, 0, 255, 255))
+                .unwrap();
+
+        let pixel = flattened.pixels()[0];
+        assert_eq!((pixel.red(), pixel.green(), pixel.blue()), (0, 0, 255));
+    }
+
+    #[test]
+    fn pdf_version_maps_to_matching_krilla_version() {
+        assert!(matches!(
+            krilla::configure::PdfVersion::from(PdfVersion::V1_4),
+            krilla::configure::PdfVersion::Pdf14
+        ));
+        assert!(matches!(
+            krilla::configure::PdfVersion::from(PdfVersion::V2_0),
+            krilla::configure::PdfVersion::Pdf20
+        ));
+        assert!(matches!(
+            krilla::configure::PdfVersion::from(PdfVersion::default()),
+            krilla::configure::PdfVersion::Pdf17
+        ));
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use clap::{Parser, ValueEnum};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 use std::fs;
 use std::path::PathBuf;
 
@@ -10,13 +10,62 @@ enum OutputFormat {
     Pdf,
     /// Output as PNG images
     Png,
+    /// Output as JPEG images (see --quality)
+    #[cfg(feature = "jpeg")]
+    Jpeg,
+    /// Output as WebP images (see --quality and --lossless)
+    #[cfg(feature = "webp")]
+    WebP,
+    /// Output as TIFF images
+    Tiff,
 }
 
-/// Split a PDF file into individual pages
+/// Target PDF version for emitted documents
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum PdfVersion {
+    #[value(name = "v1-4")]
+    V1_4,
+    #[value(name = "v1-5")]
+    V1_5,
+    #[value(name = "v1-6")]
+    V1_6,
+    #[value(name = "v1-7")]
+    V1_7,
+    #[value(name = "v2-0")]
+    V2_0,
+}
+
+impl From<PdfVersion> for pdf_handler::PdfVersion {
+    fn from(version: PdfVersion) -> Self {
+        match version {
+            PdfVersion::V1_4 => pdf_handler::PdfVersion::V1_4,
+            PdfVersion::V1_5 => pdf_handler::PdfVersion::V1_5,
+            PdfVersion::V1_6 => pdf_handler::PdfVersion::V1_6,
+            PdfVersion::V1_7 => pdf_handler::PdfVersion::V1_7,
+            PdfVersion::V2_0 => pdf_handler::PdfVersion::V2_0,
+        }
+    }
+}
+
+/// Split and merge PDF files
 #[derive(Parser, Debug)]
 #[command(name = "pdf-handler")]
-#[command(about = "Split PDF files into individual pages", long_about = None)]
-struct Args {
+#[command(about = "Split and merge PDF files", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Split a PDF file into individual pages
+    Split(SplitArgs),
+    /// Merge several PDF files into a single multi-page PDF
+    Merge(MergeArgs),
+}
+
+#[derive(ClapArgs, Debug)]
+struct SplitArgs {
     /// Input PDF file path
     #[arg(value_name = "INPUT")]
     input: PathBuf,
@@ -29,14 +78,129 @@ struct Args {
     #[arg(short, long, value_enum, default_value = "pdf")]
     format: OutputFormat,
 
-    /// DPI for PNG output (only used with --format png)
+    /// DPI for raster output (png, jpeg, webp, tiff; not used with --format pdf)
     #[arg(long, default_value = "300")]
     dpi: u32,
+
+    /// Quality for lossy raster formats (jpeg: 1-100, webp: 0.0-100.0)
+    #[cfg(any(feature = "jpeg", feature = "webp"))]
+    #[arg(long, default_value = "85")]
+    quality: f32,
+
+    /// Use lossless compression (only used with --format webp)
+    #[cfg(feature = "webp")]
+    #[arg(long)]
+    lossless: bool,
+
+    /// Target PDF version for split pages (pdf format only)
+    #[arg(long, value_enum, default_value = "v1-7")]
+    pdf_version: PdfVersion,
+
+    /// Copy Title/Author/Subject/Keywords from the source PDF into each split page (pdf format only)
+    #[arg(long)]
+    preserve_metadata: bool,
+
+    /// Retain the source outline entries that point at the extracted page (pdf format only)
+    #[arg(long)]
+    preserve_outline: bool,
+
+    /// Render pages concurrently instead of one at a time (recommended for large documents)
+    #[arg(long)]
+    parallel: bool,
+
+    /// Pages to extract, e.g. "1-3,5,10-" (default: all pages)
+    #[arg(long, value_parser = parse_page_selection)]
+    pages: Option<pdf_handler::PageSelection>,
+
+    /// Flatten transparency onto this background color before encoding, e.g. "#ffffff" (raster formats only)
+    #[arg(long, value_parser = parse_color)]
+    background: Option<tiny_skia::Color>,
+
+    /// Disable anti-aliasing when rendering raster output
+    #[arg(long)]
+    no_antialias: bool,
+}
+
+/// Parse a page selection like "1-3,5,10-" into a `PageSelection`.
+fn parse_page_selection(s: &str) -> Result<pdf_handler::PageSelection, String> {
+    use pdf_handler::{PageRange, PageSelection};
+
+    let mut ranges = Vec::new();
+    for part in s.split(',') {
+        let part = part.trim();
+        if part.is_empty() {
+            return Err(format!("Empty page entry in '{}'", s));
+        }
+
+        let range = if let Some((start, end)) = part.split_once('-') {
+            let start = start.trim();
+            let end = end.trim();
+            if end.is_empty() {
+                let start = start
+                    .parse()
+                    .map_err(|_| format!("Invalid page number in '{}'", part))?;
+                PageRange::From(start)
+            } else {
+                let start = start
+                    .parse()
+                    .map_err(|_| format!("Invalid page number in '{}'", part))?;
+                let end = end
+                    .parse()
+                    .map_err(|_| format!("Invalid page number in '{}'", part))?;
+                PageRange::Range(start, end)
+            }
+        } else {
+            let page = part
+                .parse()
+                .map_err(|_| format!("Invalid page number in '{}'", part))?;
+            PageRange::Single(page)
+        };
+
+        ranges.push(range);
+    }
+
+    Ok(PageSelection::Pages(ranges))
+}
+
+/// Parse a `#rrggbb` or `#rrggbbaa` hex color into a `tiny_skia::Color`.
+fn parse_color(s: &str) -> Result<tiny_skia::Color, String> {
+    let hex = s.trim_start_matches('#');
+    let channel = |range: std::ops::Range<usize>| -> Result<u8, String> {
+        u8::from_str_radix(
+            hex.get(range.clone())
+                .ok_or_else(|| format!("Invalid color '{}'", s))?,
+            16,
+        )
+        .map_err(|_| format!("Invalid color '{}'", s))
+    };
+
+    let (r, g, b) = (channel(0..2)?, channel(2..4)?, channel(4..6)?);
+    let a = if hex.len() >= 8 { channel(6..8)? } else { 255 };
+
+    Ok(tiny_skia::Color::from_rgba8(r, g, b, a))
+}
+
+#[derive(ClapArgs, Debug)]
+struct MergeArgs {
+    /// Input PDF file paths, merged in the order given
+    #[arg(value_name = "INPUT", required = true, num_args = 1..)]
+    inputs: Vec<PathBuf>,
+
+    /// Output file path for the merged PDF
+    #[arg(short, long, default_value = "merged.pdf")]
+    output: PathBuf,
 }
 
 fn main() -> Result<()> {
-    let args = Args::parse();
+    let cli = Cli::parse();
 
+    match cli.command {
+        Command::Split(args) => split(args),
+        Command::Merge(args) => merge(args),
+    }
+}
+
+fn split(args: SplitArgs) -> Result<()> {
     // Read the input PDF file
     let pdf_data = fs::read(&args.input)
         .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
@@ -45,11 +209,54 @@ fn main() -> Result<()> {
     let lib_format = match args.format {
         OutputFormat::Pdf => pdf_handler::OutputFormat::Pdf,
         OutputFormat::Png => pdf_handler::OutputFormat::Png,
+        #[cfg(feature = "jpeg")]
+        OutputFormat::Jpeg => pdf_handler::OutputFormat::Jpeg {
+            quality: args.quality.round() as u8,
+        },
+        #[cfg(feature = "webp")]
+        OutputFormat::WebP => pdf_handler::OutputFormat::WebP {
+            quality: args.quality,
+            lossless: args.lossless,
+        },
+        OutputFormat::Tiff => pdf_handler::OutputFormat::Tiff,
     };
 
-    // Split the PDF using the library
-    let results = pdf_handler::split_pdf(&pdf_data, lib_format, args.dpi)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let write_options = pdf_handler::PdfWriteOptions {
+        preserve_metadata: args.preserve_metadata,
+        version: args.pdf_version.into(),
+        preserve_outline: args.preserve_outline,
+        ..Default::default()
+    };
+
+    let split_options = pdf_handler::SplitOptions {
+        pages: args.pages.unwrap_or_default(),
+        dpi: args.dpi as f32,
+        background: args.background,
+        antialias: !args.no_antialias,
+    };
+
+    // Split the PDF using the library. `--parallel` shares a `RenderCache` across the rayon
+    // workers so they don't each re-resolve the same standard font independently.
+    let results = if args.parallel {
+        let font_config = pdf_handler::FontConfig::cached(pdf_handler::RenderCache::default());
+        pdf_handler::split_pdf_parallel(
+            &pdf_data,
+            lib_format,
+            &write_options,
+            &font_config,
+            &split_options,
+        )
+    } else {
+        let font_config = pdf_handler::FontConfig::default();
+        pdf_handler::split_pdf(
+            &pdf_data,
+            lib_format,
+            &write_options,
+            &font_config,
+            &split_options,
+        )
+    }
+    .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     println!("PDF has {} page(s)", results.len());
 
@@ -63,10 +270,14 @@ fn main() -> Result<()> {
 
     // Write each page to disk
     for result in &results {
-        // Convert MIME type to file extension
+        // Convert the result's format tag (legacy "pdf"/"png", MIME type otherwise) to a file
+        // extension
         let extension = match result.format.as_str() {
-            "application/pdf" => "pdf",
-            "image/png" => "png",
+            "pdf" => "pdf",
+            "png" => "png",
+            "image/jpeg" => "jpg",
+            "image/webp" => "webp",
+            "image/tiff" => "tiff",
             _ => "bin",
         };
 
@@ -90,3 +301,73 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+fn merge(args: MergeArgs) -> Result<()> {
+    // Read each input PDF file
+    let mut inputs = Vec::with_capacity(args.inputs.len());
+    for input in &args.inputs {
+        let data = fs::read(input)
+            .with_context(|| format!("Failed to read input file: {}", input.display()))?;
+        inputs.push(data);
+    }
+
+    // Merge the PDFs using the library
+    let merged = pdf_handler::merge_pdfs(&inputs).map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    fs::write(&args.output, &merged)
+        .with_context(|| format!("Failed to write output file: {}", args.output.display()))?;
+
+    println!(
+        "Merged {} file(s) into {}",
+        args.inputs.len(),
+        args.output.display()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_comma_separated_pages_and_ranges() {
+        let selection = parse_page_selection("1-3,5,10-").unwrap();
+        assert!(selection.contains(1));
+        assert!(selection.contains(3));
+        assert!(!selection.contains(4));
+        assert!(selection.contains(5));
+        assert!(!selection.contains(9));
+        assert!(selection.contains(10));
+        assert!(selection.contains(1_000));
+    }
+
+    #[test]
+    fn rejects_empty_page_entry() {
+        assert!(parse_page_selection("1,,3").is_err());
+    }
+
+    #[test]
+    fn rejects_non_numeric_page() {
+        assert!(parse_page_selection("abc").is_err());
+    }
+
+    #[test]
+    fn parses_rgb_hex_color() {
+        let color = parse_color("#336699").unwrap();
+        let c = color.to_color_u8();
+        assert_eq!((c.red(), c.green(), c.blue(), c.alpha()), (0x33, 0x66, 0x99, 255));
+    }
+
+    #[test]
+    fn parses_rgba_hex_color_with_alpha() {
+        let color = parse_color("#33669980").unwrap();
+        assert_eq!(color.to_color_u8().alpha(), 0x80);
+    }
+
+    #[test]
+    fn rejects_malformed_hex_color() {
+        assert!(parse_color("#zzzzzz").is_err());
+        assert!(parse_color("#fff").is_err());
+    }
+}
@@ -0,0 +1,117 @@
+//! Page selection and render options for `split_pdf`/`split_pdf_parallel`.
+
+/// Which pages of the source document to render.
+///
+/// 1-indexed, matching `PageResult::page_number`. The CLI parses strings like `1-3,5,10-` into
+/// this type; the library itself only deals with the already-structured selection.
+#[derive(Debug, Clone, Default)]
+pub enum PageSelection {
+    /// Render every page.
+    #[default]
+    All,
+    /// Render only the given ranges, in the order pages appear in the source document.
+    Pages(Vec<PageRange>),
+}
+
+/// A single entry in a [`PageSelection::Pages`] list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageRange {
+    /// A single page number, e.g. `5`.
+    Single(usize),
+    /// An inclusive range, e.g. `1-3`.
+    Range(usize, usize),
+    /// An open-ended range to the end of the document, e.g. `10-`.
+    From(usize),
+}
+
+impl PageRange {
+    fn contains(&self, page_number: usize) -> bool {
+        match *self {
+            PageRange::Single(n) => page_number == n,
+            PageRange::Range(start, end) => (start..=end).contains(&page_number),
+            PageRange::From(start) => page_number >= start,
+        }
+    }
+}
+
+impl PageSelection {
+    /// Whether `page_number` (1-indexed) should be rendered.
+    pub fn contains(&self, page_number: usize) -> bool {
+        match self {
+            PageSelection::All => true,
+            PageSelection::Pages(ranges) => ranges.iter().any(|r| r.contains(page_number)),
+        }
+    }
+}
+
+/// Render options for `split_pdf`/`split_pdf_parallel`.
+#[derive(Debug, Clone)]
+pub struct SplitOptions {
+    /// Which pages to render; non-selected pages are skipped entirely rather than rendered and
+    /// discarded, so extracting page 500 of a 1000-page file doesn't render the other 999.
+    pub pages: PageSelection,
+    /// Rendering resolution in dots per inch (raster formats only).
+    pub dpi: f32,
+    /// Flatten transparency onto this color before encoding (raster formats only). Leave unset
+    /// to keep an alpha channel where the output format supports one.
+    pub background: Option<tiny_skia::Color>,
+    /// Whether to anti-alias the rendered output (raster formats only).
+    pub antialias: bool,
+}
+
+impl Default for SplitOptions {
+    fn default() -> Self {
+        SplitOptions {
+            pages: PageSelection::All,
+            dpi: 300.0,
+            background: None,
+            antialias: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_selection_contains_every_page() {
+        assert!(PageSelection::All.contains(1));
+        assert!(PageSelection::All.contains(9_999));
+    }
+
+    #[test]
+    fn single_matches_only_that_page() {
+        let selection = PageSelection::Pages(vec![PageRange::Single(5)]);
+        assert!(!selection.contains(4));
+        assert!(selection.contains(5));
+        assert!(!selection.contains(6));
+    }
+
+    #[test]
+    fn range_is_inclusive_on_both_ends() {
+        let selection = PageSelection::Pages(vec![PageRange::Range(2, 4)]);
+        assert!(!selection.contains(1));
+        assert!(selection.contains(2));
+        assert!(selection.contains(4));
+        assert!(!selection.contains(5));
+    }
+
+    #[test]
+    fn from_has_no_upper_bound() {
+        let selection = PageSelection::Pages(vec![PageRange::From(10)]);
+        assert!(!selection.contains(9));
+        assert!(selection.contains(10));
+        assert!(selection.contains(1_000_000));
+    }
+
+    #[test]
+    fn multiple_ranges_are_unioned() {
+        let selection =
+            PageSelection::Pages(vec![PageRange::Single(1), PageRange::Range(5, 6)]);
+        assert!(selection.contains(1));
+        assert!(!selection.contains(3));
+        assert!(selection.contains(5));
+        assert!(selection.contains(6));
+    }
+}